@@ -1,14 +1,146 @@
 use crate::dynamic::RigidBody;
 use crate::math::{Rotation, Vector};
-use rapier::dynamics::RigidBodySet;
+use rapier::dynamics::{MassProperties as RMassProperties, RigidBodySet};
 use rapier::geometry::{
-    Collider as RCollider, ColliderBuilder, ColliderHandle, ColliderSet, Shape,
+    Collider as RCollider, ColliderBuilder, ColliderHandle, ColliderSet,
+    CoefficientCombineRule as RCoefficientCombineRule, InteractionGroups as RInteractionGroups,
+    Shape,
 };
-use rapier::math::Isometry;
+use rapier::math::{Isometry, Point};
+use rapier::parry::transformation::vhacd::{VHACDParameters as RVHACDParameters, VHACD};
 use std::cell::RefCell;
 use std::rc::Rc;
 use wasm_bindgen::prelude::*;
 
+#[wasm_bindgen]
+#[derive(Copy, Clone)]
+/// The parameters controlling the approximate convex decomposition (VHACD) of a mesh.
+pub struct VHACDParameters {
+    /// The concavity threshold below which a part of the decomposition is considered convex enough.
+    pub concavity: f32,
+    /// The number of voxels used to discretize the mesh being decomposed.
+    pub resolution: u32,
+    /// The granularity of the search for the best clipping plane for each voxel cluster.
+    pub plane_downsampling: u32,
+    /// The precision of the convex-hull generation used for each part of the decomposition.
+    pub convex_hull_downsampling: u32,
+    /// The maximum number of convex hulls produced by the decomposition.
+    pub max_convex_hulls: u32,
+}
+
+#[wasm_bindgen]
+impl VHACDParameters {
+    /// Creates a new set of VHACD parameters initialized with their default values.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        RVHACDParameters::default().into()
+    }
+}
+
+impl Default for VHACDParameters {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<VHACDParameters> for RVHACDParameters {
+    fn from(params: VHACDParameters) -> Self {
+        Self {
+            concavity: params.concavity,
+            resolution: params.resolution,
+            plane_downsampling: params.plane_downsampling,
+            convex_hull_downsampling: params.convex_hull_downsampling,
+            max_convex_hulls: params.max_convex_hulls,
+            ..Self::default()
+        }
+    }
+}
+
+impl From<RVHACDParameters> for VHACDParameters {
+    fn from(params: RVHACDParameters) -> Self {
+        Self {
+            concavity: params.concavity,
+            resolution: params.resolution,
+            plane_downsampling: params.plane_downsampling,
+            convex_hull_downsampling: params.convex_hull_downsampling,
+            max_convex_hulls: params.max_convex_hulls,
+        }
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Copy, Clone)]
+/// A bit mask identifying groups for interaction filtering between colliders.
+///
+/// Two colliders `a` and `b` are allowed to interact iff.
+/// `(a.memberships & b.filter) != 0 && (b.memberships & a.filter) != 0`.
+pub struct InteractionGroups {
+    /// The groups this collider is part of.
+    pub memberships: u32,
+    /// The groups this collider can interact with.
+    pub filter: u32,
+}
+
+#[wasm_bindgen]
+impl InteractionGroups {
+    /// Creates a new interaction groups with the given memberships and filter.
+    #[wasm_bindgen(constructor)]
+    pub fn new(memberships: u32, filter: u32) -> Self {
+        Self { memberships, filter }
+    }
+}
+
+impl From<InteractionGroups> for RInteractionGroups {
+    fn from(groups: InteractionGroups) -> Self {
+        Self::new(groups.memberships, groups.filter)
+    }
+}
+
+impl From<RInteractionGroups> for InteractionGroups {
+    fn from(groups: RInteractionGroups) -> Self {
+        Self {
+            memberships: groups.memberships,
+            filter: groups.filter,
+        }
+    }
+}
+
+#[wasm_bindgen]
+/// A string describing the rule used to combine the friction or restitution coefficients of two
+/// colliders in contact.
+pub enum CoefficientCombineRule {
+    /// The two coefficients are averaged.
+    Average = "Average",
+    /// The smallest coefficient is used.
+    Min = "Min",
+    /// The two coefficients are multiplied.
+    Multiply = "Multiply",
+    /// The largest coefficient is used.
+    Max = "Max",
+}
+
+impl From<CoefficientCombineRule> for RCoefficientCombineRule {
+    fn from(rule: CoefficientCombineRule) -> Self {
+        match rule {
+            CoefficientCombineRule::Average => Self::Average,
+            CoefficientCombineRule::Min => Self::Min,
+            CoefficientCombineRule::Multiply => Self::Multiply,
+            CoefficientCombineRule::Max => Self::Max,
+        }
+    }
+}
+
+impl From<RCoefficientCombineRule> for CoefficientCombineRule {
+    fn from(rule: RCoefficientCombineRule) -> Self {
+        match rule {
+            RCoefficientCombineRule::Average => Self::Average,
+            RCoefficientCombineRule::Min => Self::Min,
+            RCoefficientCombineRule::Multiply => Self::Multiply,
+            RCoefficientCombineRule::Max => Self::Max,
+        }
+    }
+}
+
 #[wasm_bindgen]
 /// A string describing the type of the collider's shape.
 pub enum ShapeType {
@@ -19,6 +151,127 @@ pub enum ShapeType {
     Triangle = "Triangle",
     Trimesh = "Trimesh",
     HeightField = "HeightField",
+    Compound = "Compound",
+}
+
+/// The local isometry and shape type of a single sub-shape of a compound collider.
+#[wasm_bindgen]
+#[derive(Copy, Clone)]
+pub struct CompoundChild {
+    /// The local translation of this sub-shape relative to its parent compound collider.
+    pub translation: Vector,
+    /// The local rotation of this sub-shape relative to its parent compound collider.
+    pub rotation: Rotation,
+    /// The type of this sub-shape.
+    pub shape_type: ShapeType,
+}
+
+#[wasm_bindgen]
+#[derive(Copy, Clone)]
+/// The mass, local center of mass, and angular inertia resolved for a collider.
+pub struct MassProperties {
+    /// The total mass.
+    pub mass: f32,
+    /// The center of mass, expressed in the collider's local space.
+    pub local_center_of_mass: Vector,
+    /// The angular inertia along this collider's principal inertia axes.
+    #[cfg(feature = "dim2")]
+    pub principal_inertia: f32,
+    /// The angular inertia along this collider's principal inertia axes.
+    #[cfg(feature = "dim3")]
+    pub principal_inertia: Vector,
+}
+
+#[cfg(feature = "dim2")]
+impl From<RMassProperties> for MassProperties {
+    fn from(mprops: RMassProperties) -> Self {
+        Self {
+            mass: mprops.mass(),
+            local_center_of_mass: Vector(mprops.local_com.coords),
+            principal_inertia: mprops.principal_inertia(),
+        }
+    }
+}
+
+#[cfg(feature = "dim3")]
+impl From<RMassProperties> for MassProperties {
+    fn from(mprops: RMassProperties) -> Self {
+        Self {
+            mass: mprops.mass(),
+            local_center_of_mass: Vector(mprops.local_com.coords),
+            principal_inertia: Vector(mprops.principal_inertia()),
+        }
+    }
+}
+
+/// The `ShapeType` of the given shape.
+fn shape_type_of(shape: &Shape) -> ShapeType {
+    match shape {
+        Shape::Ball(_) => ShapeType::Ball,
+        Shape::Polygon(_) => ShapeType::Polygon,
+        Shape::Cuboid(_) => ShapeType::Cuboid,
+        Shape::Capsule(_) => ShapeType::Capsule,
+        Shape::Triangle(_) => ShapeType::Triangle,
+        Shape::Trimesh(_) => ShapeType::Trimesh,
+        Shape::HeightField(_) => ShapeType::HeightField,
+        Shape::Compound(_) => ShapeType::Compound,
+    }
+}
+
+/// Builds a `ColliderDesc` with a compound shape made of the given sub-shapes, keeping this
+/// crate's default collider properties (density, friction, etc.) for everything else.
+fn compound_desc(parts: Vec<(Isometry<f32>, Shape)>) -> Result<ColliderDesc, JsValue> {
+    if parts.is_empty() {
+        return Err(JsValue::from_str(
+            "a compound shape must have at least one part",
+        ));
+    }
+
+    let base = ColliderBuilder::ball(1.0);
+    Ok(ColliderBuilder {
+        shape: Shape::Compound(parts),
+        ..base
+    }
+    .into())
+}
+
+/// Splits a flat index buffer into triangle index triples, or an error if its length isn't a
+/// multiple of 3.
+fn triangles_from_flat_indices(indices: &[u32]) -> Result<Vec<[u32; 3]>, JsValue> {
+    if indices.len() % 3 != 0 {
+        return Err(JsValue::from_str(&format!(
+            "`indices` length must be a multiple of 3, got {}",
+            indices.len()
+        )));
+    }
+
+    Ok(indices.chunks(3).map(|i| [i[0], i[1], i[2]]).collect())
+}
+
+/// Converts a flat array of coordinates into a vector of points, in this crate's dimension, or
+/// an error if its length isn't a multiple of that dimension.
+fn points_from_flat_array(coords: &[f32]) -> Result<Vec<Point<f32>>, JsValue> {
+    #[cfg(feature = "dim2")]
+    let dim = 2;
+    #[cfg(feature = "dim3")]
+    let dim = 3;
+
+    if coords.len() % dim != 0 {
+        return Err(JsValue::from_str(&format!(
+            "`vertices` length must be a multiple of {}, got {}",
+            dim,
+            coords.len()
+        )));
+    }
+
+    #[cfg(feature = "dim2")]
+    let points = coords.chunks(2).map(|p| Point::new(p[0], p[1])).collect();
+    #[cfg(feature = "dim3")]
+    let points = coords
+        .chunks(3)
+        .map(|p| Point::new(p[0], p[1], p[2]))
+        .collect();
+    Ok(points)
 }
 
 #[wasm_bindgen]
@@ -60,14 +313,23 @@ impl Collider {
 
     /// The type of the shape of this collider.
     pub fn shapeType(&self) -> ShapeType {
+        self.map(|co| shape_type_of(co.shape()))
+    }
+
+    /// The sub-shapes and their local isometry if this collider has a compound shape.
+    pub fn compoundChildren(&self) -> Option<Vec<CompoundChild>> {
         self.map(|co| match co.shape() {
-            Shape::Ball(_) => ShapeType::Ball,
-            Shape::Polygon(_) => ShapeType::Polygon,
-            Shape::Cuboid(_) => ShapeType::Cuboid,
-            Shape::Capsule(_) => ShapeType::Capsule,
-            Shape::Triangle(_) => ShapeType::Triangle,
-            Shape::Trimesh(_) => ShapeType::Trimesh,
-            Shape::HeightField(_) => ShapeType::HeightField,
+            Shape::Compound(parts) => Some(
+                parts
+                    .iter()
+                    .map(|(pos, shape)| CompoundChild {
+                        translation: Vector(pos.translation.vector),
+                        rotation: Rotation(pos.rotation),
+                        shape_type: shape_type_of(shape),
+                    })
+                    .collect(),
+            ),
+            _ => None,
         })
     }
 
@@ -79,10 +341,40 @@ impl Collider {
         })
     }
 
-    /// The radius of this collider if it is has a ball shape.
+    /// The radius of this collider if it is has a ball or capsule shape.
     pub fn radius(&self) -> Option<f32> {
         self.map(|co| match co.shape() {
             Shape::Ball(b) => Some(b.radius),
+            Shape::Capsule(c) => Some(c.radius),
+            _ => None,
+        })
+    }
+
+    /// The half-height of this collider if it is has a capsule shape.
+    pub fn halfHeight(&self) -> Option<f32> {
+        self.map(|co| match co.shape() {
+            Shape::Capsule(c) => Some(c.half_height()),
+            _ => None,
+        })
+    }
+
+    /// The vertex buffer of this collider if it has a trimesh shape.
+    pub fn vertices(&self) -> Option<Vec<f32>> {
+        self.map(|co| match co.shape() {
+            Shape::Trimesh(t) => Some(
+                t.vertices()
+                    .iter()
+                    .flat_map(|p| p.coords.iter().copied())
+                    .collect(),
+            ),
+            _ => None,
+        })
+    }
+
+    /// The index buffer of this collider if it has a trimesh shape.
+    pub fn indices(&self) -> Option<Vec<u32>> {
+        self.map(|co| match co.shape() {
+            Shape::Trimesh(t) => Some(t.indices().iter().flat_map(|i| i.iter().copied()).collect()),
             _ => None,
         })
     }
@@ -123,6 +415,59 @@ impl Collider {
     pub fn density(&self) -> f32 {
         self.map(|co| co.density())
     }
+
+    /// The collision groups of this collider.
+    pub fn collisionGroups(&self) -> InteractionGroups {
+        self.map(|co| co.collision_groups.into())
+    }
+
+    /// The solver groups of this collider.
+    pub fn solverGroups(&self) -> InteractionGroups {
+        self.map(|co| co.solver_groups.into())
+    }
+
+    /// The rule used to combine this collider's friction coefficient with its contacting collider's one.
+    pub fn frictionCombineRule(&self) -> CoefficientCombineRule {
+        self.map(|co| co.friction_combine_rule.into())
+    }
+
+    /// The rule used to combine this collider's restitution coefficient with its contacting collider's one.
+    pub fn restitutionCombineRule(&self) -> CoefficientCombineRule {
+        self.map(|co| co.restitution_combine_rule.into())
+    }
+
+    /// The user-defined data attached to this collider, as its `[lo, hi]` 64-bit halves.
+    pub fn userData(&self) -> Vec<u64> {
+        self.map(|co| vec![co.user_data as u64, (co.user_data >> 64) as u64])
+    }
+
+    /// The mass, local center of mass, and angular inertia resolved for this collider, taking
+    /// into account any mass or mass-properties override.
+    pub fn massProperties(&self) -> MassProperties {
+        self.map(|co| co.mass_properties().into())
+    }
+}
+
+#[wasm_bindgen]
+#[derive(Clone)]
+/// One child shape of a compound collider, along with its local placement.
+pub struct ColliderDescChild {
+    translation: Vector,
+    rotation: Rotation,
+    shape: ColliderDesc,
+}
+
+#[wasm_bindgen]
+impl ColliderDescChild {
+    /// Creates a new compound-collider child from its local translation, rotation and shape.
+    #[wasm_bindgen(constructor)]
+    pub fn new(translation: Vector, rotation: Rotation, shape: ColliderDesc) -> Self {
+        Self {
+            translation,
+            rotation,
+            shape,
+        }
+    }
 }
 
 #[wasm_bindgen]
@@ -139,6 +484,12 @@ pub struct ColliderDesc {
     delta: Isometry<f32>,
     /// Is this collider a sensor?
     pub is_sensor: bool,
+    collision_groups: RInteractionGroups,
+    solver_groups: RInteractionGroups,
+    friction_combine_rule: RCoefficientCombineRule,
+    restitution_combine_rule: RCoefficientCombineRule,
+    user_data: u128,
+    mass_properties: Option<RMassProperties>,
 }
 
 impl From<ColliderDesc> for ColliderBuilder {
@@ -150,6 +501,12 @@ impl From<ColliderDesc> for ColliderBuilder {
             restitution: desc.restitution,
             delta: desc.delta,
             is_sensor: desc.is_sensor,
+            collision_groups: desc.collision_groups,
+            solver_groups: desc.solver_groups,
+            friction_combine_rule: desc.friction_combine_rule,
+            restitution_combine_rule: desc.restitution_combine_rule,
+            user_data: desc.user_data,
+            mass_properties: desc.mass_properties,
         }
     }
 }
@@ -163,6 +520,12 @@ impl From<ColliderBuilder> for ColliderDesc {
             restitution: bldr.restitution,
             delta: bldr.delta,
             is_sensor: bldr.is_sensor,
+            collision_groups: bldr.collision_groups,
+            solver_groups: bldr.solver_groups,
+            friction_combine_rule: bldr.friction_combine_rule,
+            restitution_combine_rule: bldr.restitution_combine_rule,
+            user_data: bldr.user_data,
+            mass_properties: bldr.mass_properties,
         }
     }
 }
@@ -197,4 +560,230 @@ impl ColliderDesc {
     pub fn cuboid(hx: f32, hy: f32, hz: f32) -> Self {
         ColliderBuilder::cuboid(hx, hy, hz).into()
     }
+
+    /// Creates a new collider descriptor with a capsule shape.
+    ///
+    /// # Parameters
+    /// - `half_height`: the half-height of the capsule, along its local `y` axis.
+    /// - `radius`: the radius of the capsule's rounded parts.
+    pub fn capsule(half_height: f32, radius: f32) -> Self {
+        ColliderBuilder::capsule_y(half_height, radius).into()
+    }
+
+    /// Creates a new collider descriptor with a triangle shape.
+    ///
+    /// # Parameters
+    /// - `a`: the first vertex of the triangle.
+    /// - `b`: the second vertex of the triangle.
+    /// - `c`: the third vertex of the triangle.
+    pub fn triangle(a: Vector, b: Vector, c: Vector) -> Self {
+        ColliderBuilder::triangle(Point::from(a.0), Point::from(b.0), Point::from(c.0)).into()
+    }
+
+    /// Creates a new collider descriptor with a triangle mesh shape.
+    ///
+    /// # Parameters
+    /// - `vertices`: the flat array of vertex coordinates of the mesh.
+    /// - `indices`: the flat array of triangle vertex indices of the mesh.
+    pub fn trimesh(vertices: Vec<f32>, indices: Vec<u32>) -> Result<ColliderDesc, JsValue> {
+        let vertices = points_from_flat_array(&vertices)?;
+        let indices = triangles_from_flat_indices(&indices)?;
+        Ok(ColliderBuilder::trimesh(vertices, indices).into())
+    }
+
+    /// Creates a new collider descriptor with a heightfield shape.
+    ///
+    /// # Parameters
+    /// - `heights`: the heights of the heightfield, along its local `y` axis, provided as a flat
+    ///   array arranged in column-major order.
+    /// - `scale`: the scale factor applied to the heightfield.
+    #[cfg(feature = "dim2")]
+    pub fn heightfield(heights: Vec<f32>, scale: Vector) -> Self {
+        ColliderBuilder::heightfield(heights, scale.0).into()
+    }
+
+    /// Creates a new collider descriptor with a heightfield shape.
+    ///
+    /// # Parameters
+    /// - `nrows`: the number of rows of cells of the heightfield; the heights matrix has
+    ///   `nrows + 1` rows.
+    /// - `ncols`: the number of columns of cells of the heightfield; the heights matrix has
+    ///   `ncols + 1` columns.
+    /// - `heights`: the heights of the heightfield, provided as a flat array in column-major order.
+    /// - `scale`: the scale factor applied to the heightfield.
+    #[cfg(feature = "dim3")]
+    pub fn heightfield(
+        nrows: usize,
+        ncols: usize,
+        heights: Vec<f32>,
+        scale: Vector,
+    ) -> Result<ColliderDesc, JsValue> {
+        let expected_len = (nrows + 1) * (ncols + 1);
+        if heights.len() != expected_len {
+            return Err(JsValue::from_str(&format!(
+                "`heights` must contain (nrows + 1) * (ncols + 1) = {} elements, got {}",
+                expected_len,
+                heights.len()
+            )));
+        }
+
+        let heights = na::DMatrix::from_vec(nrows + 1, ncols + 1, heights);
+        Ok(ColliderBuilder::heightfield(heights, scale.0).into())
+    }
+
+    /// Creates a new collider descriptor with a convex hull shape obtained from the given points.
+    ///
+    /// Returns `None` if the convex hull computation failed (e.g. the input points are degenerate).
+    ///
+    /// # Parameters
+    /// - `points`: the flat array of point coordinates the convex hull is computed from.
+    pub fn convexHull(points: Vec<f32>) -> Result<Option<ColliderDesc>, JsValue> {
+        let points = points_from_flat_array(&points)?;
+        Ok(ColliderBuilder::convex_hull(&points).map(|bldr| bldr.into()))
+    }
+
+    /// Creates a new collider descriptor with a compound shape obtained by approximate convex
+    /// decomposition (VHACD) of the given mesh.
+    ///
+    /// This voxelizes the mesh and recursively splits it along the clipping plane that best
+    /// reduces concavity, until every part's concavity falls below `params.concavity`; each
+    /// resulting part's convex hull becomes one sub-shape of the compound.
+    ///
+    /// # Parameters
+    /// - `vertices`: the flat array of vertex coordinates of the mesh to decompose.
+    /// - `indices`: the flat array of triangle vertex indices of the mesh to decompose.
+    /// - `params`: the parameters controlling the decomposition.
+    pub fn convexDecomposition(
+        vertices: Vec<f32>,
+        indices: Vec<u32>,
+        params: VHACDParameters,
+    ) -> Result<ColliderDesc, JsValue> {
+        let points = points_from_flat_array(&vertices)?;
+        let indices = triangles_from_flat_indices(&indices)?;
+        let params: RVHACDParameters = params.into();
+        let hulls = VHACD::new(&params, &points, &indices, false)
+            .compute_exact_convex_hulls(&points, &indices);
+
+        let parts: Vec<_> = hulls
+            .into_iter()
+            .filter_map(|(verts, _)| {
+                ColliderBuilder::convex_hull(&verts).map(|bldr| (Isometry::identity(), bldr.shape))
+            })
+            .collect();
+
+        compound_desc(parts)
+    }
+
+    /// Creates a new collider descriptor with a compound shape made of the given child shapes,
+    /// each placed at its own local isometry relative to this collider.
+    ///
+    /// # Parameters
+    /// - `parts`: the child shapes, with their local translation and rotation.
+    pub fn compound(parts: Vec<ColliderDescChild>) -> Result<ColliderDesc, JsValue> {
+        let parts = parts
+            .into_iter()
+            .map(|child| {
+                let position = Isometry::from_parts(child.translation.0.into(), child.rotation.0);
+                (position, child.shape.shape)
+            })
+            .collect();
+
+        compound_desc(parts)
+    }
+
+    /// Sets the collision groups used by this collider to filter contact pairs.
+    ///
+    /// Two colliders `a` and `b` are allowed to interact iff.
+    /// `(a.memberships & b.filter) != 0 && (b.memberships & a.filter) != 0`.
+    ///
+    /// # Parameters
+    /// - `memberships`: the groups this collider is part of.
+    /// - `filter`: the groups this collider can interact with.
+    pub fn setCollisionGroups(&mut self, memberships: u32, filter: u32) {
+        self.collision_groups = RInteractionGroups::new(memberships, filter);
+    }
+
+    /// Sets the solver groups used by this collider to filter contact constraints.
+    ///
+    /// Two colliders `a` and `b` have their contact constraints solved iff.
+    /// `(a.memberships & b.filter) != 0 && (b.memberships & a.filter) != 0`.
+    ///
+    /// # Parameters
+    /// - `memberships`: the groups this collider is part of.
+    /// - `filter`: the groups this collider can interact with.
+    pub fn setSolverGroups(&mut self, memberships: u32, filter: u32) {
+        self.solver_groups = RInteractionGroups::new(memberships, filter);
+    }
+
+    /// Sets the rule used to combine this collider's friction coefficient with its contacting collider's one.
+    pub fn setFrictionCombineRule(&mut self, rule: CoefficientCombineRule) {
+        self.friction_combine_rule = rule.into();
+    }
+
+    /// Sets the rule used to combine this collider's restitution coefficient with its contacting collider's one.
+    pub fn setRestitutionCombineRule(&mut self, rule: CoefficientCombineRule) {
+        self.restitution_combine_rule = rule.into();
+    }
+
+    /// Sets the user-defined data attached to the collider to be constructed.
+    ///
+    /// # Parameters
+    /// - `lo`: the low 64 bits of the 128-bit user data.
+    /// - `hi`: the high 64 bits of the 128-bit user data.
+    pub fn setUserData(&mut self, lo: u64, hi: u64) {
+        self.user_data = ((hi as u128) << 64) | lo as u128;
+    }
+
+    /// Overrides the mass of the collider to be constructed, independently of its density.
+    ///
+    /// The center of mass and angular inertia are still derived from the collider's shape.
+    ///
+    /// Returns an error if the shape has zero volume at unit density (e.g. a `Triangle` shape in
+    /// 3D), since the mass can't be rescaled from it in that case; use `setMassProperties` instead.
+    pub fn setMass(&mut self, mass: f32) -> Result<(), JsValue> {
+        // Computed at unit density so the shape-relative inertia can be rescaled to the mass
+        // the caller asked for, instead of the inertia implied by this descriptor's density.
+        let unit_mprops = self.shape.mass_properties(1.0);
+        if unit_mprops.mass() <= 0.0 {
+            return Err(JsValue::from_str(
+                "cannot override the mass of a zero-volume shape; use `setMassProperties` instead",
+            ));
+        }
+
+        let principal_inertia = unit_mprops.principal_inertia() * (mass / unit_mprops.mass());
+        self.mass_properties = Some(RMassProperties::new(
+            unit_mprops.local_com,
+            mass,
+            principal_inertia,
+        ));
+        Ok(())
+    }
+
+    /// Overrides the mass, center of mass, and angular inertia of the collider to be constructed,
+    /// independently of its density and shape.
+    ///
+    /// # Parameters
+    /// - `mass`: the total mass.
+    /// - `com`: the center of mass, expressed in the collider's local space.
+    /// - `principal_inertia`: the angular inertia along the collider's principal inertia axes.
+    #[cfg(feature = "dim2")]
+    pub fn setMassProperties(&mut self, mass: f32, com: Vector, principal_inertia: f32) {
+        self.mass_properties = Some(RMassProperties::new(Point::from(com.0), mass, principal_inertia));
+    }
+
+    /// Overrides the mass, center of mass, and angular inertia of the collider to be constructed,
+    /// independently of its density and shape.
+    ///
+    /// # Parameters
+    /// - `mass`: the total mass.
+    /// - `com`: the center of mass, expressed in the collider's local space.
+    /// - `principal_inertia`: the angular inertia along the collider's principal inertia axes.
+    #[cfg(feature = "dim3")]
+    pub fn setMassProperties(&mut self, mass: f32, com: Vector, principal_inertia: Vector) {
+        self.mass_properties = Some(RMassProperties::new(
+            Point::from(com.0),
+            mass,
+            principal_inertia.0,
+        ));
+    }
 }